@@ -148,6 +148,173 @@ const sidebar = [
       link('Document Handling And Text Extraction', '/guides/content/document-handling-and-text-extraction')
     ]
   },
+  {
+    text: 'Rust Bindings',
+    collapsed: true,
+    items: [
+      link('Rust Bindings Overview', '/guides/rust/ffi/overview'),
+      link('Mock FFI Backend', '/guides/rust/ffi/mock-backend'),
+      link('Zero-Copy Byte Buffers', '/guides/rust/ffi/byte-buffers'),
+      link('Async Plugin Execution', '/guides/rust/ffi/async-plugin-execution'),
+      link('Executor Runtime Configuration', '/guides/rust/ffi/executor-runtime'),
+      link('Wide-String FFI Variants', '/guides/rust/ffi/wide-string-variants'),
+      link('Native-Less Mode', '/guides/rust/ffi/native-less-mode')
+    ]
+  },
+  {
+    text: 'Rust Streaming',
+    collapsed: true,
+    items: [
+      link('Binary Frames', '/guides/rust/streaming/binary-frames'),
+      link('Heartbeat And Stall Detection', '/guides/rust/streaming/heartbeat-and-stall-detection'),
+      link('Event Filtering And Selectors', '/guides/rust/streaming/event-filtering'),
+      link('Server-Sent Events Adapter', '/guides/rust/streaming/sse-adapter'),
+      link('WebSocket Bridge', '/guides/rust/streaming/websocket-bridge'),
+      link('Multi-Subscriber Stream Tee', '/guides/rust/streaming/multi-subscriber-tee'),
+      link('Stream Recording And Replay', '/guides/rust/streaming/recording-and-replay'),
+      link('Per-Stream Metrics', '/guides/rust/streaming/stream-metrics'),
+      link('Resumable Streaming', '/guides/rust/streaming/resumable-streaming'),
+      link('Streaming Context Registry', '/guides/rust/streaming/context-registry'),
+      link('Creation Progress Streaming', '/guides/rust/streaming/creation-progress'),
+      link('Event Schema Versioning', '/guides/rust/streaming/event-schema-versioning')
+    ]
+  },
+  {
+    text: 'Rust Configuration',
+    collapsed: true,
+    items: [
+      link('Configuration Profiles', '/guides/rust/configuration/profiles'),
+      link('Typed Settings And Validation', '/guides/rust/configuration/typed-settings'),
+      link('Secret Sources', '/guides/rust/configuration/secret-sources'),
+      link('Config Search Paths', '/guides/rust/configuration/search-paths'),
+      link('Per-Provider Sections', '/guides/rust/configuration/provider-sections'),
+      link('Hot Reload', '/guides/rust/configuration/hot-reload'),
+      link('Programmatic Settings Builder', '/guides/rust/configuration/settings-builder'),
+      link('dotenv Support', '/guides/rust/configuration/dotenv'),
+      link('Encrypted Secrets', '/guides/rust/configuration/encrypted-secrets'),
+      link('Schema Export And Doctor', '/guides/rust/configuration/schema-and-doctor'),
+      link('Locale Configuration', '/guides/rust/configuration/locale'),
+      link('Graceful Degradation', '/guides/rust/configuration/graceful-degradation'),
+      link('Per-Conversation Credentials', '/guides/rust/configuration/per-conversation-credentials')
+    ]
+  },
+  {
+    text: 'Rust Conversations',
+    collapsed: true,
+    items: [
+      link('Schema-Validated Output', '/guides/rust/conversations/validated-output'),
+      link('Prompt Templates', '/guides/rust/conversations/prompt-templates'),
+      link('Structured Output Derive', '/guides/rust/conversations/structured-outputs'),
+      link('Speech-To-Text Input', '/guides/rust/conversations/speech-to-text-input'),
+      link('Image Generation', '/guides/rust/conversations/image-generation'),
+      link('Runtime Tool Toggles', '/guides/rust/conversations/runtime-tool-toggles'),
+      link('Multi-Part Sends', '/guides/rust/conversations/send-messages'),
+      link('Tool Budget Exhaustion', '/guides/rust/conversations/tool-budget-exhaustion'),
+      link('Response Envelope', '/guides/rust/conversations/response-envelope'),
+      link('Parallel Tool Execution', '/guides/rust/conversations/parallel-tool-execution'),
+      link('Tool Argument Validation', '/guides/rust/conversations/tool-argument-validation'),
+      link('Memory Pinning', '/guides/rust/conversations/memory-pinning'),
+      link('Prompt Snapshots And Diffing', '/guides/rust/conversations/prompt-snapshots'),
+      link('Wire-Level Exchange Debugging', '/guides/rust/conversations/exchange-debug'),
+      link('Tool Artifacts', '/guides/rust/conversations/artifacts')
+    ]
+  },
+  {
+    text: 'Rust Runtime Services',
+    collapsed: true,
+    items: [
+      link('Memory And Vector Stores', '/guides/rust/runtime/memory-and-vector-stores'),
+      link('Embeddings', '/guides/rust/runtime/embeddings'),
+      link('Task Scheduler', '/guides/rust/runtime/scheduler'),
+      link('Response Caching', '/guides/rust/runtime/response-cache'),
+      link('SQLite Persistence', '/guides/rust/runtime/sqlite-persistence'),
+      link('Session Manager', '/guides/rust/runtime/session-manager'),
+      link('Batch Processing', '/guides/rust/runtime/batch-processing'),
+      link('Knowledge-Graph Memory', '/guides/rust/runtime/knowledge-graph-memory'),
+      link('Filesystem Triggers', '/guides/rust/runtime/filesystem-triggers'),
+      link('Background Agent Tasks', '/guides/rust/runtime/background-tasks'),
+      link('Run Checkpointing', '/guides/rust/runtime/checkpointing'),
+      link('Agent And Conversation Pooling', '/guides/rust/runtime/object-pooling')
+    ]
+  },
+  {
+    text: 'Rust Observability',
+    collapsed: true,
+    items: [
+      link('OpenTelemetry Tracing', '/guides/rust/observability/opentelemetry'),
+      link('Cost Tracking And Budgets', '/guides/rust/observability/cost-tracking'),
+      link('Local Token Counting', '/guides/rust/observability/token-counting'),
+      link('JSONL Event Logging', '/guides/rust/observability/jsonl-event-log'),
+      link('Conversation Analytics', '/guides/rust/observability/conversation-analytics'),
+      link('Prometheus Metrics', '/guides/rust/observability/prometheus-metrics')
+    ]
+  },
+  {
+    text: 'Rust Safety And Limits',
+    collapsed: true,
+    items: [
+      link('Guardrails', '/guides/rust/safety/guardrails'),
+      link('Process-Isolated Tools', '/guides/rust/safety/process-isolated-tools'),
+      link('Redaction And PII Scrubbing', '/guides/rust/safety/redaction'),
+      link('Rate And Turn Limits', '/guides/rust/safety/rate-and-turn-limits')
+    ]
+  },
+  {
+    text: 'Rust Plugins',
+    collapsed: true,
+    items: [
+      link('Git Tools Plugin', '/guides/rust/plugins/git-tools'),
+      link('External C-ABI Plugins', '/guides/rust/plugins/extern-c-adapter'),
+      link('Plugin Instance Naming', '/guides/rust/plugins/instance-naming'),
+      link('Built-In Plugins', '/guides/rust/plugins/builtin-plugins'),
+      link('Time Tools Plugin', '/guides/rust/plugins/time-tools'),
+      link('Expression Evaluator', '/guides/rust/plugins/expression-evaluator'),
+      link('Email Tools Plugin', '/guides/rust/plugins/email-tools'),
+      link('Code Execution Plugin', '/guides/rust/plugins/code-execution'),
+      link('Tool Error Taxonomy', '/guides/rust/plugins/error-taxonomy'),
+      link('Struct Parameters', '/guides/rust/plugins/struct-parameters'),
+      link('Enum Parameters', '/guides/rust/plugins/enum-parameters'),
+      link('Parameter Defaults', '/guides/rust/plugins/param-defaults'),
+      link('Parameter Constraints', '/guides/rust/plugins/param-constraints'),
+      link('Instance-Based Executors', '/guides/rust/plugins/instance-executors'),
+      link('Result Return Types', '/guides/rust/plugins/result-returns'),
+      link('Cancellation Tokens', '/guides/rust/plugins/cancellation'),
+      link('Service Injection', '/guides/rust/plugins/service-injection')
+    ]
+  },
+  {
+    text: 'Rust Multi-Agent',
+    collapsed: true,
+    items: [
+      link('Handoff And Delegation', '/guides/rust/multi-agent/handoff-and-delegation'),
+      link('Workflow Graphs', '/guides/rust/multi-agent/workflow-graphs'),
+      link('Debate And Consensus', '/guides/rust/multi-agent/debate-and-consensus')
+    ]
+  },
+  {
+    text: 'Rust Hosting And Interfaces',
+    collapsed: true,
+    items: [
+      link('The hpd CLI', '/guides/rust/hosting/cli'),
+      link('Interactive REPL', '/guides/rust/hosting/repl'),
+      link('Embedded HTTP Server', '/guides/rust/hosting/http-server'),
+      link('gRPC Service', '/guides/rust/hosting/grpc'),
+      link('Approval Queue', '/guides/rust/hosting/approval-queue'),
+      link('Bot Adapters', '/guides/rust/hosting/bot-adapters'),
+      link('Webhooks', '/guides/rust/hosting/webhooks')
+    ]
+  },
+  {
+    text: 'Rust Testing And Evaluation',
+    collapsed: true,
+    items: [
+      link('Evaluation Harness', '/guides/rust/testing/evaluation-harness'),
+      link('Benchmark Runner', '/guides/rust/testing/benchmark-runner'),
+      link('Mock Chat Provider', '/guides/rust/testing/mock-provider'),
+      link('Provider Record And Replay', '/guides/rust/testing/vcr-cassettes'),
+      link('Schema Fixture Generation', '/guides/rust/testing/schema-fixtures')
+    ]
+  },
   {
     text: 'Audio',
     collapsed: true,